@@ -49,26 +49,54 @@ fn validate_cpf(cpf: &str) -> bool
     digits[10] == second_check_digit
 }
 
-/// Função para validar CNPJ seguindo o algoritmo oficial
+/// Converte um caractere (dígito ou letra A-Z) no valor usado no cálculo dos
+/// dígitos verificadores do CNPJ alfanumérico: dígitos valem 0-9, letras
+/// maiúsculas valem (código ASCII - 48), ou seja, 17-42.
+fn cnpj_char_weight_value(c: char) -> Option<u32>
+{
+    if c.is_ascii_digit()
+    {
+        c.to_digit(10)
+    }
+    else if c.is_ascii_uppercase()
+    {
+        Some(c as u32 - 48)
+    }
+    else
+    {
+        None
+    }
+}
+
+/// Função para validar CNPJ (numérico ou alfanumérico) seguindo o algoritmo oficial
+/// `cnpj` deve conter exatamente os 14 caracteres já extraídos (ver `extract_cnpj_chars`)
 fn validate_cnpj(cnpj: &str) -> bool
 {
-    let digits: Vec<u32> = cnpj
-        .chars()
-        .filter(|c| c.is_ascii_digit())
-        .filter_map(|c| c.to_digit(10))
-        .collect();
+    let chars: Vec<char> = cnpj.chars().collect();
 
-    if digits.len() != 14
+    if chars.len() != 14
     {
         return false;
     }
 
-    // Verifica se todos os dígitos são iguais (casos inválidos)
-    if digits.iter().all(|&x| x == digits[0])
+    // Verifica se todos os caracteres são iguais (casos inválidos)
+    if chars.iter().all(|&c| c == chars[0])
+    {
+        return false;
+    }
+
+    // Os dois dígitos verificadores permanecem sempre numéricos
+    if !chars[12].is_ascii_digit() || !chars[13].is_ascii_digit()
     {
         return false;
     }
 
+    let values: Vec<u32> = match chars.iter().map(|&c| cnpj_char_weight_value(c)).collect()
+    {
+        Some(values) => values,
+        None => return false,
+    };
+
     // Pesos para o primeiro dígito verificador
     let weights1 = [5, 4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2];
 
@@ -76,13 +104,13 @@ fn validate_cnpj(cnpj: &str) -> bool
     let mut sum = 0;
     for i in 0..12
     {
-        sum += digits[i] * weights1[i];
+        sum += values[i] * weights1[i];
     }
     let remainder = sum % 11;
     let first_check_digit = if remainder < 2 { 0 } else { 11 - remainder };
 
     // Verifica o primeiro dígito verificador
-    if digits[12] != first_check_digit
+    if values[12] != first_check_digit
     {
         return false;
     }
@@ -94,13 +122,13 @@ fn validate_cnpj(cnpj: &str) -> bool
     sum = 0;
     for i in 0..13
     {
-        sum += digits[i] * weights2[i];
+        sum += values[i] * weights2[i];
     }
     let remainder = sum % 11;
     let second_check_digit = if remainder < 2 { 0 } else { 11 - remainder };
 
     // Verifica o segundo dígito verificador
-    digits[13] == second_check_digit
+    values[13] == second_check_digit
 }
 
 /// Função para extrair apenas dígitos
@@ -109,37 +137,42 @@ fn extract_digits(value: &str) -> String
     value.chars().filter(|c| c.is_ascii_digit()).collect()
 }
 
-/// Função para identificar se é CPF ou CNPJ
+/// Função para extrair dígitos e letras (preservando letras, ao contrário de
+/// `extract_digits`), usada para suportar o CNPJ alfanumérico
+fn extract_cnpj_chars(value: &str) -> String
+{
+    value.chars().filter(|c| c.is_ascii_alphanumeric()).map(|c| c.to_ascii_uppercase()).collect()
+}
+
+/// Valida um CPF (11 dígitos) ou um CNPJ numérico/alfanumérico (14 caracteres)
+fn validate_cpf_or_cnpj(value: &str) -> bool
+{
+    let digits = extract_digits(value);
+    if digits.len() == 11 && validate_cpf(&digits)
+    {
+        return true;
+    }
+
+    let chars = extract_cnpj_chars(value);
+    chars.len() == 14 && validate_cnpj(&chars)
+}
+
+/// Função para identificar se é CPF ou CNPJ (CNPJ pode ser numérico ou alfanumérico)
 fn identify_cpf_cnpj(value: &str) -> Option<&'static str>
 {
     let digits = extract_digits(value);
+    if digits.len() == 11 && validate_cpf(&digits)
+    {
+        return Some("CPF");
+    }
 
-    match digits.len()
+    let chars = extract_cnpj_chars(value);
+    if chars.len() == 14 && validate_cnpj(&chars)
     {
-        11 =>
-        {
-            if validate_cpf(&digits)
-            {
-                Some("CPF")
-            }
-            else
-            {
-                None
-            }
-        },
-        14 =>
-        {
-            if validate_cnpj(&digits)
-            {
-                Some("CNPJ")
-            }
-            else
-            {
-                None
-            }
-        },
-        _ => None,
+        return Some("CNPJ");
     }
+
+    None
 }
 
 /// Função para formatar CPF
@@ -156,19 +189,19 @@ fn format_cpf(cpf: &str) -> String
     }
 }
 
-/// Função para formatar CNPJ
+/// Função para formatar CNPJ (numérico ou alfanumérico)
 fn format_cnpj(cnpj: &str) -> String
 {
-    let digits = extract_digits(cnpj);
-    if digits.len() == 14
+    let chars = extract_cnpj_chars(cnpj);
+    if chars.len() == 14
     {
         format!(
             "{}.{}.{}/{}-{}",
-            &digits[0..2],
-            &digits[2..5],
-            &digits[5..8],
-            &digits[8..12],
-            &digits[12..14]
+            &chars[0..2],
+            &chars[2..5],
+            &chars[5..8],
+            &chars[8..12],
+            &chars[12..14]
         )
     }
     else
@@ -182,15 +215,7 @@ fn format_cnpj(cnpj: &str) -> String
 pub fn validate_cpf_cnpj(inputs: &[Series]) -> PolarsResult<Series>
 {
     let ca = inputs[0].str()?;
-    let out: BooleanChunked = ca.apply_nonnull_values_generic(DataType::Boolean, |s| {
-        let digits = extract_digits(s);
-        match digits.len()
-        {
-            11 => validate_cpf(&digits),
-            14 => validate_cnpj(&digits),
-            _ => false,
-        }
-    });
+    let out: BooleanChunked = ca.apply_nonnull_values_generic(DataType::Boolean, |s| validate_cpf_or_cnpj(s));
     Ok(out.into_series())
 }
 
@@ -210,36 +235,115 @@ pub fn format_cpf_cnpj(inputs: &[Series]) -> PolarsResult<Series>
     let ca = inputs[0].str()?;
     let out: StringChunked = ca.apply_nonnull_values_generic(DataType::String, |s| {
         let digits = extract_digits(s);
-        match digits.len()
+        if digits.len() == 11 && validate_cpf(&digits)
         {
-            11 =>
-            {
-                if validate_cpf(&digits)
-                {
-                    format_cpf(s)
-                }
-                else
-                {
-                    s.to_string()
-                }
-            },
-            14 =>
-            {
-                if validate_cnpj(&digits)
-                {
-                    format_cnpj(s)
-                }
-                else
-                {
-                    s.to_string()
-                }
-            },
-            _ => s.to_string(),
+            return format_cpf(s);
         }
+
+        let chars = extract_cnpj_chars(s);
+        if chars.len() == 14 && validate_cnpj(&chars)
+        {
+            return format_cnpj(s);
+        }
+
+        s.to_string()
     });
     Ok(out.into_series())
 }
 
+/// Caractere que pode fazer parte de um candidato a CPF/CNPJ dentro de um texto
+/// livre (dígitos e a pontuação usada por `format_cpf`/`format_cnpj`)
+fn is_doc_candidate_char(c: char) -> bool
+{
+    c.is_ascii_digit() || matches!(c, '.' | '-' | '/')
+}
+
+/// Varre um texto livre coletando todo CPF ou CNPJ numérico válido embutido na
+/// célula, já normalizado e marcado com o tipo (`"CPF"`/`"CNPJ"`). Como os
+/// candidatos contêm apenas dígitos e pontuação, CNPJs alfanuméricos não são encontrados
+fn extract_cpf_cnpj_from_text(text: &str) -> Vec<(String, &'static str)>
+{
+    let chars: Vec<char> = text.chars().collect();
+    let n = chars.len();
+    let mut results = Vec::new();
+
+    let mut i = 0;
+    while i < n
+    {
+        if !is_doc_candidate_char(chars[i])
+        {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        while i < n && is_doc_candidate_char(chars[i])
+        {
+            i += 1;
+        }
+        let end = i;
+
+        // Um candidato não pode estar colado a outros dígitos fora do run,
+        // senão um CNPJ de 14 dígitos poderia ser fatiado de um número maior
+        let before_ok = start == 0 || !chars[start - 1].is_ascii_digit();
+        let after_ok = end == n || !chars[end].is_ascii_digit();
+        if !before_ok || !after_ok
+        {
+            continue;
+        }
+
+        let candidate: String = chars[start..end].iter().collect();
+        if let Some(kind) = identify_cpf_cnpj(&candidate)
+        {
+            let formatted = if kind == "CPF" { format_cpf(&candidate) } else { format_cnpj(&candidate) };
+            results.push((formatted, kind));
+        }
+    }
+
+    results
+}
+
+/// Define o tipo de saída (`List[Struct{value: String, kind: String}]`) da
+/// expressão `extract_cpf_cnpj`
+fn list_cpf_cnpj_dtype(input_fields: &[Field]) -> PolarsResult<Field>
+{
+    let fields = vec![Field::new("value".into(), DataType::String), Field::new("kind".into(), DataType::String)];
+    Ok(Field::new(input_fields[0].name().clone(), DataType::List(Box::new(DataType::Struct(fields)))))
+}
+
+/// Extrai todo CPF ou CNPJ válido embutido em um texto livre, retornando cada
+/// ocorrência normalizada e marcada com seu tipo
+#[polars_expr(output_type_func=list_cpf_cnpj_dtype)]
+pub fn extract_cpf_cnpj(inputs: &[Series]) -> PolarsResult<Series>
+{
+    let ca = inputs[0].str()?;
+    let struct_dtype =
+        DataType::Struct(vec![Field::new("value".into(), DataType::String), Field::new("kind".into(), DataType::String)]);
+    let mut builder = get_list_builder(&struct_dtype, ca.len(), ca.len(), ca.name().clone())?;
+
+    for opt_text in ca.into_iter()
+    {
+        match opt_text
+        {
+            Some(text) =>
+            {
+                let matches = extract_cpf_cnpj_from_text(text);
+                let values: Vec<&str> = matches.iter().map(|(value, _)| value.as_str()).collect();
+                let kinds: Vec<&str> = matches.iter().map(|(_, kind)| *kind).collect();
+
+                let value_series = Series::new("value".into(), values);
+                let kind_series = Series::new("kind".into(), kinds);
+                let row = StructChunked::from_series("".into(), value_series.len(), [value_series, kind_series].iter())?
+                    .into_series();
+                builder.append_series(&row)?;
+            },
+            None => builder.append_null(),
+        }
+    }
+
+    Ok(builder.finish().into_series())
+}
+
 #[cfg(test)]
 mod tests
 {
@@ -259,10 +363,31 @@ mod tests
         assert!(!validate_cnpj("11111111111111"));
     }
 
+    #[test]
+    fn test_cnpj_alfanumerico()
+    {
+        assert!(validate_cnpj("12ABC34501DE35"));
+        assert!(!validate_cnpj("12ABC34501DE00"));
+        // Dígitos verificadores precisam permanecer numéricos
+        assert!(!validate_cnpj("12ABC34501DEAB"));
+    }
+
     #[test]
     fn test_format()
     {
         assert_eq!(format_cpf("50542983800"), "505.429.838-00".to_string());
         assert_eq!(format_cnpj("60204424000108"), "60.204.424/0001-08".to_string());
+        assert_eq!(format_cnpj("12abc34501de35"), "12.ABC.345/01DE-35".to_string());
+    }
+
+    #[test]
+    fn test_extract_cpf_cnpj_from_text()
+    {
+        let text = "Cliente 505.429.838-00, fornecedor 60.204.424/0001-08, inválido 111.111.111-11.";
+        let matches = extract_cpf_cnpj_from_text(text);
+        assert_eq!(matches, vec![
+            ("505.429.838-00".to_string(), "CPF"),
+            ("60.204.424/0001-08".to_string(), "CNPJ"),
+        ]);
     }
 }
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::sync::LazyLock;
 
 use polars::prelude::*;
@@ -17,6 +18,90 @@ static PHONE_FLEXIBLE_PATTERNS: LazyLock<Vec<Regex>> = LazyLock::new(|| {
     ]
 });
 
+/// Tabela estática DDD -> (UF, Região), construída uma única vez
+static DDD_TABLE: LazyLock<HashMap<u32, (&'static str, &'static str)>> = LazyLock::new(|| {
+    const SUDESTE: &str = "Sudeste";
+    const SUL: &str = "Sul";
+    const CENTRO_OESTE: &str = "Centro-Oeste";
+    const NORTE: &str = "Norte";
+    const NORDESTE: &str = "Nordeste";
+
+    HashMap::from([
+        // Sudeste
+        (11, ("SP", SUDESTE)),
+        (12, ("SP", SUDESTE)),
+        (13, ("SP", SUDESTE)),
+        (14, ("SP", SUDESTE)),
+        (15, ("SP", SUDESTE)),
+        (16, ("SP", SUDESTE)),
+        (17, ("SP", SUDESTE)),
+        (18, ("SP", SUDESTE)),
+        (19, ("SP", SUDESTE)),
+        (21, ("RJ", SUDESTE)),
+        (22, ("RJ", SUDESTE)),
+        (24, ("RJ", SUDESTE)),
+        (27, ("ES", SUDESTE)),
+        (28, ("ES", SUDESTE)),
+        (31, ("MG", SUDESTE)),
+        (32, ("MG", SUDESTE)),
+        (33, ("MG", SUDESTE)),
+        (34, ("MG", SUDESTE)),
+        (35, ("MG", SUDESTE)),
+        (37, ("MG", SUDESTE)),
+        (38, ("MG", SUDESTE)),
+        // Sul
+        (41, ("PR", SUL)),
+        (42, ("PR", SUL)),
+        (43, ("PR", SUL)),
+        (44, ("PR", SUL)),
+        (45, ("PR", SUL)),
+        (46, ("PR", SUL)),
+        (47, ("SC", SUL)),
+        (48, ("SC", SUL)),
+        (49, ("SC", SUL)),
+        (51, ("RS", SUL)),
+        (53, ("RS", SUL)),
+        (54, ("RS", SUL)),
+        (55, ("RS", SUL)),
+        // Centro-Oeste
+        (61, ("DF", CENTRO_OESTE)),
+        (62, ("GO", CENTRO_OESTE)),
+        (64, ("GO", CENTRO_OESTE)),
+        (65, ("MT", CENTRO_OESTE)),
+        (66, ("MT", CENTRO_OESTE)),
+        (67, ("MS", CENTRO_OESTE)),
+        // Norte
+        (63, ("TO", NORTE)),
+        (68, ("AC", NORTE)),
+        (69, ("RO", NORTE)),
+        (91, ("PA", NORTE)),
+        (93, ("PA", NORTE)),
+        (94, ("PA", NORTE)),
+        (92, ("AM", NORTE)),
+        (97, ("AM", NORTE)),
+        (95, ("RR", NORTE)),
+        (96, ("AP", NORTE)),
+        // Nordeste
+        (71, ("BA", NORDESTE)),
+        (73, ("BA", NORDESTE)),
+        (74, ("BA", NORDESTE)),
+        (75, ("BA", NORDESTE)),
+        (77, ("BA", NORDESTE)),
+        (79, ("SE", NORDESTE)),
+        (81, ("PE", NORDESTE)),
+        (87, ("PE", NORDESTE)),
+        (82, ("AL", NORDESTE)),
+        (83, ("PB", NORDESTE)),
+        (84, ("RN", NORDESTE)),
+        (85, ("CE", NORDESTE)),
+        (88, ("CE", NORDESTE)),
+        (86, ("PI", NORDESTE)),
+        (89, ("PI", NORDESTE)),
+        (98, ("MA", NORDESTE)),
+        (99, ("MA", NORDESTE)),
+    ])
+});
+
 /// Função para validar telefone brasileiro
 /// Formatos aceitos: +5516997184720, +5511987654321, etc.
 /// Padrão: +55 + código de área (2 dígitos) + 9 (opcional) + número (8 dígitos)
@@ -99,3 +184,249 @@ pub fn format_phone_expr(inputs: &[Series]) -> PolarsResult<Series>
     });
     Ok(out.into_series())
 }
+
+/// Caractere que pode fazer parte de um candidato a telefone dentro de um texto livre
+/// (dígitos e os separadores comumente usados para digitar telefones)
+fn is_phone_candidate_char(c: char) -> bool
+{
+    c.is_ascii_digit() || matches!(c, ' ' | '-' | '(' | ')' | '.' | '+')
+}
+
+/// Remove tudo de um candidato exceto dígitos e o `+` de código de país
+fn clean_phone_digits(candidate: &str) -> String
+{
+    candidate.chars().filter(|c| c.is_ascii_digit() || *c == '+').collect()
+}
+
+/// Normaliza um telefone já validado para a forma canônica `+55DDNNNNNNNNN`
+/// (`+55DDNNNNNNNN` para fixo com `+55` sem o 9º dígito), com a mesma extração
+/// de dígitos usada por `format_phone`, sem a pontuação
+fn canonical_phone(phone: &str) -> Option<String>
+{
+    if !validate_phone_flexible(phone)
+    {
+        return None;
+    }
+
+    let digits: String = phone.chars().filter(|c| c.is_ascii_digit()).collect();
+    let local = match digits.len()
+    {
+        13 if digits.starts_with("55") => digits[2..].to_string(),
+        12 if digits.starts_with("55") => digits[2..].to_string(), // +55 fixo sem o 9º dígito
+        11 => digits,
+        12 if digits.starts_with('0') => digits[1..].to_string(),
+        _ => return None,
+    };
+
+    Some(format!("+55{local}"))
+}
+
+/// Varre um texto livre coletando todos os telefones brasileiros válidos encontrados,
+/// normalizados e sem duplicatas (preservando a ordem de ocorrência)
+fn extract_phones_from_text(text: &str) -> Vec<String>
+{
+    let chars: Vec<char> = text.chars().collect();
+    let n = chars.len();
+    let mut seen: std::collections::HashSet<String> = std::collections::HashSet::new();
+    let mut phones = Vec::new();
+
+    let mut i = 0;
+    while i < n
+    {
+        if !is_phone_candidate_char(chars[i])
+        {
+            i += 1;
+            continue;
+        }
+
+        let raw_start = i;
+        while i < n && is_phone_candidate_char(chars[i])
+        {
+            i += 1;
+        }
+        let raw_end = i;
+
+        // Espaços nas bordas do run não fazem parte do número em si (apenas o
+        // separam do texto ao redor); descarta-os antes de checar a fronteira
+        let mut start = raw_start;
+        let mut end = raw_end;
+        while start < end && chars[start].is_whitespace()
+        {
+            start += 1;
+        }
+        while end > start && chars[end - 1].is_whitespace()
+        {
+            end -= 1;
+        }
+        if start == end
+        {
+            continue;
+        }
+
+        // Um candidato não pode estar colado a letras/dígitos vizinhos fora do run
+        let before_ok = start == 0 || !chars[start - 1].is_alphanumeric();
+        let after_ok = end == n || !chars[end].is_alphanumeric();
+        if !before_ok || !after_ok
+        {
+            continue;
+        }
+
+        let run: String = chars[start..end].iter().collect();
+
+        // Um run pode conter mais de um telefone colado apenas por espaço
+        // (ex.: "16997184720 11987654321"). Quando cada palavra do run já
+        // valida sozinha, trata cada uma como um candidato distinto; caso
+        // contrário o espaço faz parte da formatação de um único número
+        // (ex.: "+55 16 99718-4720") e o run inteiro é tratado como um só candidato.
+        let tokens: Vec<String> = run.split_whitespace().map(clean_phone_digits).collect();
+        if tokens.len() > 1 && tokens.iter().all(|token| canonical_phone(token).is_some())
+        {
+            for token in &tokens
+            {
+                if let Some(normalized) = canonical_phone(token)
+                {
+                    if seen.insert(normalized.clone())
+                    {
+                        phones.push(normalized);
+                    }
+                }
+            }
+            continue;
+        }
+
+        if let Some(normalized) = canonical_phone(&clean_phone_digits(&run))
+        {
+            if seen.insert(normalized.clone())
+            {
+                phones.push(normalized);
+            }
+        }
+    }
+
+    phones
+}
+
+/// Define o tipo de saída (`List[String]`) da expressão `extract_phones`
+fn list_string_dtype(input_fields: &[Field]) -> PolarsResult<Field>
+{
+    Ok(Field::new(input_fields[0].name().clone(), DataType::List(Box::new(DataType::String))))
+}
+
+/// Extrai todos os telefones brasileiros válidos de um texto livre, normalizados
+/// para `+55DDNNNNNNNNN` (ou `+55DDNNNNNNNN` para fixo com `+55` sem o 9º dígito)
+#[polars_expr(output_type_func=list_string_dtype)]
+pub fn extract_phones(inputs: &[Series]) -> PolarsResult<Series>
+{
+    let ca = inputs[0].str()?;
+    let mut builder = ListStringChunkedBuilder::new(ca.name().clone(), ca.len(), ca.len() * 2);
+
+    for opt_text in ca.into_iter()
+    {
+        match opt_text
+        {
+            Some(text) => builder.append_values_iter(extract_phones_from_text(text).iter().map(|s| s.as_str())),
+            None => builder.append_null(),
+        }
+    }
+
+    Ok(builder.finish().into_series())
+}
+
+/// Recupera o DDD (código de área) de um telefone válido, independentemente de vir
+/// com `+55`, `0` na frente ou nenhum prefixo
+fn extract_ddd(phone: &str) -> Option<u32>
+{
+    if !validate_phone_flexible(phone)
+    {
+        return None;
+    }
+
+    let digits: String = phone.chars().filter(|c| c.is_ascii_digit()).collect();
+    let ddd_str = match digits.len()
+    {
+        13 if digits.starts_with("55") => &digits[2..4],
+        12 if digits.starts_with("55") => &digits[2..4], // +55 fixo sem o 9º dígito
+        11 => &digits[0..2],
+        12 if digits.starts_with('0') => &digits[1..3],
+        _ => return None,
+    };
+
+    ddd_str.parse::<u32>().ok()
+}
+
+/// Define o tipo de saída (`Struct{uf: String, region: String}`) da expressão `phone_region`
+fn phone_region_dtype(input_fields: &[Field]) -> PolarsResult<Field>
+{
+    let fields = vec![Field::new("uf".into(), DataType::String), Field::new("region".into(), DataType::String)];
+    Ok(Field::new(input_fields[0].name().clone(), DataType::Struct(fields)))
+}
+
+/// Mapeia um telefone brasileiro válido para a UF e a região derivadas do seu DDD.
+/// Quando o DDD é desconhecido ou o telefone é inválido ou nulo, `uf` e `region`
+/// saem nulos; o struct da linha em si não é marcado como nulo
+#[polars_expr(output_type_func=phone_region_dtype)]
+pub fn phone_region(inputs: &[Series]) -> PolarsResult<Series>
+{
+    let ca = inputs[0].str()?;
+
+    let (uf, region): (Vec<Option<&str>>, Vec<Option<&str>>) = ca
+        .into_iter()
+        .map(|opt_s| {
+            opt_s
+                .and_then(extract_ddd)
+                .and_then(|ddd| DDD_TABLE.get(&ddd))
+                .map_or((None, None), |&(uf, region)| (Some(uf), Some(region)))
+        })
+        .unzip();
+
+    let uf = Series::new("uf".into(), uf);
+    let region = Series::new("region".into(), region);
+    StructChunked::from_series("phone_region".into(), uf.len(), [uf, region].iter()).map(|ca| ca.into_series())
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn test_extract_phones_landline_com_mais_55()
+    {
+        let text = "Fixo: +55 (11) 3555-0100.";
+        assert_eq!(extract_phones_from_text(text), vec!["+551135550100".to_string()]);
+    }
+
+    #[test]
+    fn test_extract_phones_numeros_separados_por_espaco()
+    {
+        let text = "16997184720 11987654321";
+        assert_eq!(
+            extract_phones_from_text(text),
+            vec!["+5516997184720".to_string(), "+5511987654321".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_extract_phones_formatado_com_espacos_internos()
+    {
+        let text = "Ligue +55 16 99718-4720 hoje";
+        assert_eq!(extract_phones_from_text(text), vec!["+5516997184720".to_string()]);
+    }
+
+    #[test]
+    fn test_extract_ddd_variantes()
+    {
+        assert_eq!(extract_ddd("+5516997184720"), Some(16));
+        assert_eq!(extract_ddd("+551135550100"), Some(11)); // fixo com +55, sem o 9
+        assert_eq!(extract_ddd("016997184720"), Some(16));
+        assert_eq!(extract_ddd("não é telefone"), None);
+    }
+
+    #[test]
+    fn test_ddd_table_lookup()
+    {
+        assert_eq!(DDD_TABLE.get(&11), Some(&("SP", "Sudeste")));
+        assert_eq!(DDD_TABLE.get(&61), Some(&("DF", "Centro-Oeste")));
+        assert_eq!(DDD_TABLE.get(&0), None);
+    }
+}
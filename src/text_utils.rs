@@ -2,6 +2,7 @@ use std::fmt::Write;
 
 use polars::prelude::*;
 use pyo3_polars::derive::polars_expr;
+use serde::Deserialize;
 
 /// Converte uma string para pig latin
 fn pig_latin_str(value: &str, output: &mut String)
@@ -79,3 +80,202 @@ pub fn title_case_expr(inputs: &[Series]) -> PolarsResult<Series>
     let out: StringChunked = ca.apply_nonnull_values_generic(DataType::String, |s| title_case(s));
     Ok(out.into_series())
 }
+
+#[derive(Deserialize)]
+pub struct FormatNumberBrKwargs
+{
+    decimals: usize,
+    #[serde(default)]
+    binary: bool,
+}
+
+/// Agrupa uma sequência de dígitos em blocos de `block_size` a partir da direita,
+/// unidos por `separator` (algoritmo de `formatNumber` do Grammalecte: fatia
+/// `nStart = max(nEnd - block_size, 0)` a cada passo)
+fn group_digits(digits: &str, block_size: usize, separator: char) -> String
+{
+    let chars: Vec<char> = digits.chars().collect();
+    let mut blocks = Vec::new();
+    let mut n_end = chars.len();
+
+    while n_end > 0
+    {
+        let n_start = n_end.saturating_sub(block_size);
+        blocks.push(chars[n_start..n_end].iter().collect::<String>());
+        n_end = n_start;
+    }
+
+    blocks.reverse();
+    blocks.join(&separator.to_string())
+}
+
+/// Ajusta a parte fracionária para ter exatamente `decimals` dígitos,
+/// truncando ou completando com zeros à direita
+fn pad_or_truncate_fraction(fraction: &str, decimals: usize) -> String
+{
+    let char_count = fraction.chars().count();
+    if char_count >= decimals
+    {
+        fraction.chars().take(decimals).collect()
+    }
+    else
+    {
+        format!("{fraction}{}", "0".repeat(decimals - char_count))
+    }
+}
+
+/// Formata um número no padrão brasileiro: milhar agrupado por `.` e separador
+/// decimal `,`. Quando `binary` é `true` e as partes inteira e fracionária contêm
+/// apenas `0`/`1`, o agrupamento passa a ser em blocos de 4 (notação binária),
+/// unidos por espaço
+fn format_number_br_value(value: &str, decimals: usize, binary: bool) -> String
+{
+    let trimmed = value.trim();
+    let negative = trimmed.starts_with('-');
+    let unsigned = trimmed.trim_start_matches(['+', '-']);
+
+    let (int_part, frac_part) = unsigned.split_once(['.', ',']).unwrap_or((unsigned, ""));
+
+    let is_binary_like = int_part.chars().all(|c| c == '0' || c == '1') && frac_part.chars().all(|c| c == '0' || c == '1');
+
+    let grouped_int = if binary && is_binary_like
+    {
+        group_digits(int_part, 4, ' ')
+    }
+    else
+    {
+        group_digits(int_part, 3, '.')
+    };
+
+    let sign = if negative { "-" } else { "" };
+    if decimals == 0
+    {
+        format!("{sign}{grouped_int}")
+    }
+    else
+    {
+        format!("{sign}{grouped_int},{}", pad_or_truncate_fraction(frac_part, decimals))
+    }
+}
+
+/// Formata uma coluna numérica (ou textual representando números) no padrão
+/// brasileiro de agrupamento de milhar e separador decimal
+#[polars_expr(output_type=String)]
+pub fn format_number_br(inputs: &[Series], kwargs: FormatNumberBrKwargs) -> PolarsResult<Series>
+{
+    let ca = inputs[0].cast(&DataType::String)?;
+    let ca = ca.str()?;
+    let out: StringChunked =
+        ca.apply_nonnull_values_generic(DataType::String, |s| format_number_br_value(s, kwargs.decimals, kwargs.binary));
+    Ok(out.into_series())
+}
+
+/// Interpreta um decimal no formato brasileiro (`.` como separador de milhar, `,`
+/// como separador decimal), tolerando símbolos de moeda e espaços em branco.
+/// Os dígitos da mantissa completa precisam caber num `u128` (senão retorna
+/// `None`); a string normalizada `[dígitos].[dígitos]` é então convertida de uma
+/// só vez pelo parser de `f64` da linguagem, sem somar dígito a dígito
+fn parse_decimal_br_value(value: &str) -> Option<f64>
+{
+    let mut cleaned: String = value.chars().filter(|c| !c.is_whitespace()).collect();
+    for symbol in ["R$", "$", "€", "£"]
+    {
+        cleaned = cleaned.replace(symbol, "");
+    }
+
+    if cleaned.is_empty() || !cleaned.chars().all(|c| c.is_ascii_digit() || matches!(c, '.' | ',' | '-' | '+'))
+    {
+        return None;
+    }
+
+    let negative = cleaned.starts_with('-');
+    let unsigned = cleaned.trim_start_matches(['+', '-']);
+    if unsigned.is_empty()
+    {
+        return None;
+    }
+
+    let (int_part, frac_part) = unsigned.rsplit_once(',').unwrap_or((unsigned, ""));
+
+    // A parte inteira só pode conter dígitos e pontos de milhar
+    if int_part.chars().any(|c| !c.is_ascii_digit() && c != '.')
+    {
+        return None;
+    }
+    if !frac_part.chars().all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let int_digits: String = int_part.chars().filter(|c| c.is_ascii_digit()).collect();
+    if int_digits.is_empty() && frac_part.is_empty()
+    {
+        return None;
+    }
+
+    // Garante que a mantissa completa cabe num inteiro antes de seguir (evita
+    // silenciosamente truncar números maiores do que um u128 comporta)
+    let _mantissa: u128 = format!("{int_digits}{frac_part}").parse().ok()?;
+
+    // A conversão final precisa ser feita pelo parser de f64 da própria linguagem
+    // (correctly-rounded): `mantissa as f64 * 10f64.powi(-n)` introduz erro de
+    // arredondamento (ex.: 123456789 * 10f64.powi(-2) != 1234567.89)
+    let normalized = if frac_part.is_empty() { int_digits } else { format!("{int_digits}.{frac_part}") };
+    let magnitude: f64 = normalized.parse().ok()?;
+    Some(if negative { -magnitude } else { magnitude })
+}
+
+/// Converte uma coluna textual com decimais no formato brasileiro (ex.:
+/// `"1.234.567,89"`, `"R$ 2.500,00"`) para `Float64`, retornando null quando o
+/// valor não pode ser interpretado
+#[polars_expr(output_type=Float64)]
+pub fn parse_decimal_br(inputs: &[Series]) -> PolarsResult<Series>
+{
+    let ca = inputs[0].str()?;
+    let out: Float64Chunked = ca.apply_generic(|opt_s| opt_s.and_then(parse_decimal_br_value));
+    Ok(out.into_series())
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn test_format_number_br_agrupamento_milhar()
+    {
+        assert_eq!(format_number_br_value("1234567.89", 2, false), "1.234.567,89".to_string());
+        assert_eq!(format_number_br_value("-1234567", 0, false), "-1.234.567".to_string());
+    }
+
+    #[test]
+    fn test_format_number_br_fracao_multibyte_nao_gera_panic()
+    {
+        assert_eq!(format_number_br_value("1,é5", 1, false), "1,é".to_string());
+        assert_eq!(format_number_br_value("1,é", 3, false), "1,é00".to_string());
+    }
+
+    #[test]
+    fn test_format_number_br_agrupamento_binario()
+    {
+        assert_eq!(format_number_br_value("10110010", 0, true), "1011 0010".to_string());
+        // Sem o modo binário ligado, trata como decimal mesmo que pareça binário
+        assert_eq!(format_number_br_value("10110010", 0, false), "10.110.010".to_string());
+    }
+
+    #[test]
+    fn test_parse_decimal_br_casos_validos()
+    {
+        assert_eq!(parse_decimal_br_value("1.234.567,89"), Some(1_234_567.89));
+        assert_eq!(parse_decimal_br_value("R$ 2.500,00"), Some(2_500.0));
+        assert_eq!(parse_decimal_br_value("-123,5"), Some(-123.5));
+    }
+
+    #[test]
+    fn test_parse_decimal_br_lixo_retorna_none()
+    {
+        assert_eq!(parse_decimal_br_value("não é número"), None);
+        assert_eq!(parse_decimal_br_value(""), None);
+        assert_eq!(parse_decimal_br_value("12,34,56"), None);
+    }
+}